@@ -0,0 +1,6 @@
+mod palette_color;
+mod rgba_color;
+pub mod raw;
+
+pub use palette_color::PaletteColor;
+pub use rgba_color::{AlphaColor, Argb8888, Rgba8888};
@@ -0,0 +1,87 @@
+//! Indexed (palette) color.
+
+use crate::pixelcolor::{raw::RawData, PixelColor};
+
+/// Indexed color backed by a palette.
+///
+/// `PaletteColor` stores a raw index (using one of the [`RawUx`](super::raw) types) together
+/// with a reference to a lookup table of up to `N` entries of some other [`PixelColor`] `C`.
+/// This mirrors the PNG `Indexed` color type, where a single sample is a palette index rather
+/// than a direct color value, and lets a 1/2/4/8-bit image address an arbitrary set of colors
+/// instead of being limited to a fixed `Gray`/`Rgb` interpretation of its bits.
+///
+/// The conversion from index to color is performed on demand by [`color`](Self::color), so no
+/// intermediate buffer of resolved colors is ever allocated.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{raw::RawU2, PaletteColor, Rgb888};
+///
+/// const PALETTE: [Rgb888; 4] = [
+///     Rgb888::new(0, 0, 0),
+///     Rgb888::new(255, 0, 0),
+///     Rgb888::new(0, 255, 0),
+///     Rgb888::new(0, 0, 255),
+/// ];
+///
+/// let pixel = PaletteColor::new(RawU2::new(2), &PALETTE);
+/// assert_eq!(pixel.color(), Rgb888::new(0, 255, 0));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PaletteColor<'a, R, C, const N: usize> {
+    index: R,
+    palette: &'a [C; N],
+}
+
+impl<'a, R, C, const N: usize> PaletteColor<'a, R, C, N>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+{
+    /// Creates a new palette color from a raw index and a palette.
+    ///
+    /// The index is not range checked against the length of `palette`; an out of range index
+    /// will panic when [`color`](Self::color) is called.
+    pub const fn new(index: R, palette: &'a [C; N]) -> Self {
+        Self { index, palette }
+    }
+
+    /// Returns the raw palette index.
+    pub fn index(&self) -> usize {
+        usize::from(self.index.into_inner())
+    }
+
+    /// Resolves this index into the color it refers to in its palette.
+    pub fn color(&self) -> C {
+        self.palette[self.index()]
+    }
+}
+
+impl<R, C, const N: usize> PixelColor for PaletteColor<'_, R, C, N>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+{
+    type Raw = R;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{raw::RawU4, Rgb888};
+
+    const PALETTE: [Rgb888; 3] = [
+        Rgb888::new(10, 20, 30),
+        Rgb888::new(40, 50, 60),
+        Rgb888::new(70, 80, 90),
+    ];
+
+    #[test]
+    fn resolves_index_to_color() {
+        let color = PaletteColor::new(RawU4::new(1), &PALETTE);
+
+        assert_eq!(color.index(), 1);
+        assert_eq!(color.color(), Rgb888::new(40, 50, 60));
+    }
+}
@@ -0,0 +1,218 @@
+//! PNG-style scanline filtering.
+//!
+//! Slow SPI/I²C links benefit from transmitting deltas between neighboring bytes rather than
+//! full raw pixel data, since the deltas usually compress or simply sum to a smaller absolute
+//! value. This module implements the five filter types used by PNG scanlines on top of the
+//! packed byte buffers produced by [`RawData::store`](super::RawData::store) /
+//! [`RawData::load`](super::RawData::load), so a sender can filter a row before transmit and a
+//! receiver can reconstruct it on the other end.
+//!
+//! Each filter predicts a byte from some combination of its left neighbor `a`, the byte above it
+//! `b`, and the byte above-and-to-the-left `c` (all treated as `0` when they fall outside the
+//! image), and stores the difference between the real byte and the prediction. `stride` is the
+//! distance in bytes between horizontally adjacent pixels; it is `1` for the sub-byte `RawUx`
+//! formats and grows with the pixel size for wider formats.
+
+/// A PNG-style scanline filter type.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Filter {
+    /// No filtering, the byte is transmitted unchanged.
+    None,
+
+    /// Each byte is stored as the difference to its left neighbor.
+    Sub,
+
+    /// Each byte is stored as the difference to the byte directly above it.
+    Up,
+
+    /// Each byte is stored as the difference to the average of its left and above neighbors.
+    Average,
+
+    /// Each byte is stored as the difference to whichever of its left, above, or above-left
+    /// neighbors the Paeth predictor selects.
+    Paeth,
+}
+
+impl Filter {
+    /// All filter types, in the order PNG assigns them filter type bytes `0..=4`.
+    pub const ALL: [Self; 5] = [
+        Self::None,
+        Self::Sub,
+        Self::Up,
+        Self::Average,
+        Self::Paeth,
+    ];
+
+    /// Filters `row` in place using `previous` (the unfiltered previous row, or an all-zero row
+    /// for the first row of an image) and `stride`, the byte distance between horizontally
+    /// adjacent pixels.
+    pub fn filter_row(self, row: &mut [u8], previous: &[u8], stride: usize) {
+        for i in (0..row.len()).rev() {
+            let a = left(row, i, stride);
+            let b = above(previous, i);
+            let c = above_left(previous, i, stride);
+
+            row[i] = row[i].wrapping_sub(self.predict(a, b, c));
+        }
+    }
+
+    /// Reverses [`filter_row`](Self::filter_row) in place, reconstructing the original row.
+    ///
+    /// `previous` must be the already reconstructed previous row (or an all-zero row for the
+    /// first row of an image), i.e. rows must be unfiltered top to bottom.
+    pub fn unfilter_row(self, row: &mut [u8], previous: &[u8], stride: usize) {
+        for i in 0..row.len() {
+            let a = left(row, i, stride);
+            let b = above(previous, i);
+            let c = above_left(previous, i, stride);
+
+            row[i] = row[i].wrapping_add(self.predict(a, b, c));
+        }
+    }
+
+    fn predict(self, a: u8, b: u8, c: u8) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Sub => a,
+            Self::Up => b,
+            Self::Average => ((u16::from(a) + u16::from(b)) / 2) as u8,
+            Self::Paeth => paeth_predictor(a, b, c),
+        }
+    }
+}
+
+fn left(row: &[u8], i: usize, stride: usize) -> u8 {
+    i.checked_sub(stride).map(|i| row[i]).unwrap_or(0)
+}
+
+fn above(previous: &[u8], i: usize) -> u8 {
+    previous.get(i).copied().unwrap_or(0)
+}
+
+fn above_left(previous: &[u8], i: usize, stride: usize) -> u8 {
+    i.checked_sub(stride)
+        .and_then(|i| previous.get(i))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// The PNG Paeth predictor: picks whichever of `a`, `b`, `c` is closest to `p = a + b - c`, with
+/// ties broken in favor of `a`, then `b`.
+fn paeth_predictor(a: u8, b: u8, c: u8) -> u8 {
+    let p = i32::from(a) + i32::from(b) - i32::from(c);
+
+    let pa = (p - i32::from(a)).abs();
+    let pb = (p - i32::from(b)).abs();
+    let pc = (p - i32::from(c)).abs();
+
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Filters `row` using whichever [`Filter`] minimizes the sum of absolute differences of the
+/// filtered bytes, treated as signed `i8` values.
+///
+/// This is the adaptive filter heuristic PNG encoders commonly use: it doesn't guarantee the
+/// smallest possible encoded size, but is a cheap approximation that works well in practice. The
+/// sum for each candidate filter is computed directly from `row`/`previous` rather than by
+/// materializing a filtered copy of the row, so this stays allocation-free, matching the rest of
+/// this `#![no_std]` crate.
+pub fn filter_row_adaptive(row: &mut [u8], previous: &[u8], stride: usize) -> Filter {
+    let mut best_filter = Filter::None;
+    let mut best_sum = u32::MAX;
+
+    for filter in Filter::ALL {
+        let sum = sum_of_absolute_differences(filter, row, previous, stride);
+
+        if sum < best_sum {
+            best_sum = sum;
+            best_filter = filter;
+        }
+    }
+
+    best_filter.filter_row(row, previous, stride);
+    best_filter
+}
+
+/// Sums the absolute differences (treated as signed `i8` values) that filtering `row` with
+/// `filter` would produce, without writing the filtered bytes anywhere.
+fn sum_of_absolute_differences(filter: Filter, row: &[u8], previous: &[u8], stride: usize) -> u32 {
+    (0..row.len())
+        .map(|i| {
+            let a = left(row, i, stride);
+            let b = above(previous, i);
+            let c = above_left(previous, i, stride);
+
+            let filtered = row[i].wrapping_sub(filter.predict(a, b, c));
+            u32::from((filtered as i8).unsigned_abs())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn none_filter_is_a_no_op() {
+        let mut row = vec![1, 2, 3, 4];
+        let original = row.clone();
+
+        Filter::None.filter_row(&mut row, &[0, 0, 0, 0], 1);
+        assert_eq!(row, original);
+
+        Filter::None.unfilter_row(&mut row, &[0, 0, 0, 0], 1);
+        assert_eq!(row, original);
+    }
+
+    #[test]
+    fn filter_and_unfilter_round_trip_for_all_filters() {
+        let previous = vec![10, 20, 30, 40];
+        let original = vec![12, 8, 250, 5];
+
+        for filter in Filter::ALL {
+            let mut row = original.clone();
+            filter.filter_row(&mut row, &previous, 1);
+            filter.unfilter_row(&mut row, &previous, 1);
+
+            assert_eq!(row, original, "filter {filter:?} did not round-trip");
+        }
+    }
+
+    #[test]
+    fn sub_filter_uses_left_neighbor_at_stride() {
+        let mut row = vec![10, 20, 30, 40];
+        let previous = vec![0, 0, 0, 0];
+
+        Filter::Sub.filter_row(&mut row, &previous, 2);
+
+        // Bytes 0 and 1 have no left neighbor within the row (stride 2), bytes 2 and 3 do.
+        assert_eq!(row, vec![10, 20, 20, 20]);
+    }
+
+    #[test]
+    fn paeth_predictor_prefers_a_on_ties() {
+        assert_eq!(paeth_predictor(5, 5, 5), 5);
+        assert_eq!(paeth_predictor(0, 0, 0), 0);
+        // p = 40 + 10 - 20 = 30, so `a` (40) and `c` (20) are equally close to it.
+        assert_eq!(paeth_predictor(40, 10, 20), 40);
+    }
+
+    #[test]
+    fn adaptive_selector_picks_a_filter_that_round_trips() {
+        let previous = vec![10, 20, 30, 40];
+        let original = vec![12, 8, 250, 5];
+
+        let mut row = original.clone();
+        let filter = filter_row_adaptive(&mut row, &previous, 1);
+        filter.unfilter_row(&mut row, &previous, 1);
+
+        assert_eq!(row, original);
+    }
+}
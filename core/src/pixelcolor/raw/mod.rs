@@ -118,9 +118,11 @@
 //! [`into_storage`]: super::IntoStorage::into_storage
 //! [`to_be_bytes`]: ToBytes::to_be_bytes
 
+mod filter;
 mod load_store;
 mod to_bytes;
 
+pub use filter::{filter_row_adaptive, Filter};
 pub use to_bytes::ToBytes;
 
 /// Out of bounds error.
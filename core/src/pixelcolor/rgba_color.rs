@@ -0,0 +1,147 @@
+//! RGB colors with an alpha channel.
+
+use crate::pixelcolor::{
+    raw::{RawU32, ToBytes},
+    PixelColor,
+};
+
+/// A color with red, green, blue and alpha channels.
+///
+/// Implemented by [`Rgba8888`] and [`Argb8888`], which only differ in the order their channels
+/// are packed into bytes. This lets code that blends colors, such as the
+/// `CompositingDrawTarget` adapter in `embedded-graphics`, stay generic over either layout.
+pub trait AlphaColor: PixelColor {
+    /// Returns the red channel value.
+    fn r(&self) -> u8;
+
+    /// Returns the green channel value.
+    fn g(&self) -> u8;
+
+    /// Returns the blue channel value.
+    fn b(&self) -> u8;
+
+    /// Returns the alpha channel value, where `0` is fully transparent and `255` is fully
+    /// opaque.
+    fn a(&self) -> u8;
+}
+
+macro_rules! impl_alpha_color {
+    ($type:ident, $r_shift:expr, $g_shift:expr, $b_shift:expr, $a_shift:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Stored as a packed 32 bit value using a [`RawU32`].
+        #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+        pub struct $type(RawU32);
+
+        impl $type {
+            /// Creates a new color.
+            pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+                Self(RawU32::new(
+                    (r as u32) << $r_shift
+                        | (g as u32) << $g_shift
+                        | (b as u32) << $b_shift
+                        | (a as u32) << $a_shift,
+                ))
+            }
+        }
+
+        impl AlphaColor for $type {
+            fn r(&self) -> u8 {
+                (self.0.into_inner() >> $r_shift) as u8
+            }
+
+            fn g(&self) -> u8 {
+                (self.0.into_inner() >> $g_shift) as u8
+            }
+
+            fn b(&self) -> u8 {
+                (self.0.into_inner() >> $b_shift) as u8
+            }
+
+            fn a(&self) -> u8 {
+                (self.0.into_inner() >> $a_shift) as u8
+            }
+        }
+
+        impl PixelColor for $type {
+            type Raw = RawU32;
+        }
+
+        impl From<RawU32> for $type {
+            fn from(data: RawU32) -> Self {
+                Self(data)
+            }
+        }
+
+        impl From<$type> for RawU32 {
+            fn from(color: $type) -> Self {
+                color.0
+            }
+        }
+
+        impl ToBytes for $type {
+            type Bytes = [u8; 4];
+
+            fn to_be_bytes(self) -> Self::Bytes {
+                self.0.into_inner().to_be_bytes()
+            }
+
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.0.into_inner().to_le_bytes()
+            }
+
+            fn to_ne_bytes(self) -> Self::Bytes {
+                self.0.into_inner().to_ne_bytes()
+            }
+        }
+    };
+}
+
+// Channel order matches the sample order used by PNG/lodepng's `Rgba` color type: red, green,
+// blue, alpha from the most to the least significant byte.
+impl_alpha_color!(
+    Rgba8888,
+    24,
+    16,
+    8,
+    0,
+    "32 bit RGB color with an alpha channel, stored as red, green, blue, alpha."
+);
+
+// Same straight (not premultiplied) alpha as `Rgba8888`, with the channels stored as alpha, red,
+// green, blue from the most to the least significant byte.
+impl_alpha_color!(
+    Argb8888,
+    16,
+    8,
+    0,
+    24,
+    "32 bit RGB color with an alpha channel, stored as alpha, red, green, blue."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgba8888_channels_round_trip() {
+        let color = Rgba8888::new(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(color.r(), 0x11);
+        assert_eq!(color.g(), 0x22);
+        assert_eq!(color.b(), 0x33);
+        assert_eq!(color.a(), 0x44);
+        assert_eq!(color.to_be_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn argb8888_channels_round_trip() {
+        let color = Argb8888::new(0x11, 0x22, 0x33, 0x44);
+
+        assert_eq!(color.r(), 0x11);
+        assert_eq!(color.g(), 0x22);
+        assert_eq!(color.b(), 0x33);
+        assert_eq!(color.a(), 0x44);
+        assert_eq!(color.to_be_bytes(), [0x44, 0x11, 0x22, 0x33]);
+    }
+}
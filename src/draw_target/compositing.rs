@@ -0,0 +1,260 @@
+use core::marker::PhantomData;
+
+use crate::{
+    draw_target::DrawTarget,
+    framebuffer::GetPixel,
+    geometry::Dimensions,
+    iterator::ContiguousIteratorExt,
+    pixelcolor::{AlphaColor, RgbColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// A draw target adapter that composites alpha-channel colors onto a readable target.
+///
+/// `CompositingDrawTarget` wraps any target that can both be drawn to and read back from (such
+/// as a [`Framebuffer`](crate::framebuffer::Framebuffer)) and performs straight source-over
+/// alpha blending for every pixel it is asked to draw: the existing pixel is read from the
+/// parent target, blended with the incoming color using its alpha channel, and the result is
+/// written back.
+///
+/// Fully opaque source pixels (`a == 255`) skip the read/blend step and are written directly, and
+/// fully transparent source pixels (`a == 0`) are skipped entirely, so drawing opaque content
+/// through this adapter is no slower than drawing straight to the parent target.
+pub struct CompositingDrawTarget<'a, T, C> {
+    parent: &'a mut T,
+    alpha_color: PhantomData<C>,
+}
+
+impl<'a, T, C> CompositingDrawTarget<'a, T, C>
+where
+    T: DrawTarget + GetPixel,
+    T::Color: RgbColor,
+    C: AlphaColor,
+{
+    /// Creates a new compositing draw target that blends onto `parent`.
+    pub fn new(parent: &'a mut T) -> Self {
+        Self {
+            parent,
+            alpha_color: PhantomData,
+        }
+    }
+
+    /// Blends `src` onto `dst` using straight source-over compositing.
+    fn blend(dst: T::Color, src: C) -> T::Color {
+        let a = u16::from(src.a());
+
+        let blend_channel = |s: u8, d: u8| -> u8 {
+            let s = u16::from(s);
+            let d = u16::from(d);
+
+            (((s * a) + (d * (255 - a)) + 127) / 255) as u8
+        };
+
+        T::Color::new(
+            blend_channel(src.r(), dst.r()),
+            blend_channel(src.g(), dst.g()),
+            blend_channel(src.b(), dst.b()),
+        )
+    }
+}
+
+#[maybe_async::maybe_async(AFIT)]
+impl<T, C> DrawTarget for CompositingDrawTarget<'_, T, C>
+where
+    T: DrawTarget + GetPixel,
+    T::Color: RgbColor,
+    C: AlphaColor,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    async fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        // Blended pixels are buffered in fixed-size chunks and flushed with a single
+        // `draw_iter` call per chunk, rather than one call per pixel: `self.parent.pixel` (a
+        // read) and `self.parent.draw_iter` (a write) can't be interleaved on every pixel
+        // without holding overlapping borrows of `self.parent`, and per-pixel calls would also
+        // defeat the point of a batching `DrawTarget` API on targets with per-call overhead.
+        const CHUNK_SIZE: usize = 32;
+
+        let mut chunk: [Option<Pixel<T::Color>>; CHUNK_SIZE] = [None; CHUNK_SIZE];
+        let mut len = 0;
+
+        for Pixel(position, color) in pixels {
+            if color.a() == 0 {
+                continue;
+            }
+
+            let out = if color.a() == u8::MAX {
+                T::Color::new(color.r(), color.g(), color.b())
+            } else if let Some(dst) = self.parent.pixel(position) {
+                Self::blend(dst, color)
+            } else {
+                continue;
+            };
+
+            chunk[len] = Some(Pixel(position, out));
+            len += 1;
+
+            if len == CHUNK_SIZE {
+                self.parent
+                    .draw_iter(chunk[..len].iter().filter_map(|pixel| *pixel))
+                    .await?;
+                len = 0;
+            }
+        }
+
+        if len > 0 {
+            self.parent
+                .draw_iter(chunk[..len].iter().filter_map(|pixel| *pixel))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(colors.into_iter().into_pixels(area)).await
+    }
+
+    async fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        if color.a() == 0 {
+            return Ok(());
+        }
+
+        if color.a() == u8::MAX {
+            return self
+                .parent
+                .fill_solid(area, T::Color::new(color.r(), color.g(), color.b()))
+                .await;
+        }
+
+        self.fill_contiguous(
+            area,
+            core::iter::repeat(color).take((area.size.width * area.size.height) as usize),
+        )
+        .await
+    }
+
+    async fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.bounding_box();
+        self.fill_solid(&area, color).await
+    }
+}
+
+impl<T: DrawTarget, C> Dimensions for CompositingDrawTarget<'_, T, C> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{OriginDimensions, Point, Size},
+        pixelcolor::{Rgb888, Rgba8888},
+    };
+
+    struct TestTarget {
+        pixels: [[Rgb888; 2]; 2],
+    }
+
+    impl TestTarget {
+        fn new(fill: Rgb888) -> Self {
+            Self {
+                pixels: [[fill; 2]; 2],
+            }
+        }
+    }
+
+    impl OriginDimensions for TestTarget {
+        fn size(&self) -> Size {
+            Size::new(2, 2)
+        }
+    }
+
+    impl GetPixel for TestTarget {
+        type Color = Rgb888;
+
+        fn pixel(&self, p: Point) -> Option<Self::Color> {
+            self.pixels.get(p.y as usize)?.get(p.x as usize).copied()
+        }
+    }
+
+    impl DrawTarget for TestTarget {
+        type Color = Rgb888;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(p, color) in pixels {
+                if let Some(row) = self.pixels.get_mut(p.y as usize) {
+                    if let Some(pixel) = row.get_mut(p.x as usize) {
+                        *pixel = color;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn fully_transparent_pixels_are_skipped() {
+        let mut target = TestTarget::new(Rgb888::new(10, 20, 30));
+        let mut compositing = CompositingDrawTarget::<_, Rgba8888>::new(&mut target);
+
+        compositing
+            .draw_iter(core::iter::once(Pixel(
+                Point::new(0, 0),
+                Rgba8888::new(255, 0, 0, 0),
+            )))
+            .unwrap();
+
+        assert_eq!(target.pixel(Point::new(0, 0)), Some(Rgb888::new(10, 20, 30)));
+    }
+
+    #[test]
+    fn fully_opaque_pixels_overwrite_directly() {
+        let mut target = TestTarget::new(Rgb888::new(10, 20, 30));
+        let mut compositing = CompositingDrawTarget::<_, Rgba8888>::new(&mut target);
+
+        compositing
+            .draw_iter(core::iter::once(Pixel(
+                Point::new(0, 0),
+                Rgba8888::new(200, 50, 25, 255),
+            )))
+            .unwrap();
+
+        assert_eq!(target.pixel(Point::new(0, 0)), Some(Rgb888::new(200, 50, 25)));
+    }
+
+    #[test]
+    fn partially_transparent_pixels_blend_with_the_destination() {
+        let mut target = TestTarget::new(Rgb888::new(0, 0, 0));
+        let mut compositing = CompositingDrawTarget::<_, Rgba8888>::new(&mut target);
+
+        compositing
+            .draw_iter(core::iter::once(Pixel(
+                Point::new(0, 0),
+                Rgba8888::new(255, 255, 255, 128),
+            )))
+            .unwrap();
+
+        // ((255 * 128) + (0 * 127) + 127) / 255 == 128
+        assert_eq!(target.pixel(Point::new(0, 0)), Some(Rgb888::new(128, 128, 128)));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    iterator::ContiguousIteratorExt,
+    pixelcolor::raw::{DataOrder, LittleEndianMsb0, RawData, RawU1},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// A draw target adapter that clips drawing through a 1-bpp mask.
+///
+/// `MaskedDrawTarget` wraps any [`DrawTarget`] together with a packed 1-bit-per-pixel mask
+/// buffer and only forwards pixels whose corresponding mask bit is set, discarding the rest.
+/// This gives arbitrary non-rectangular clip regions for sprites or irregular UI cutouts, built
+/// on the same [`RawU1`] + [`DataOrder`] packing already used to store [`BinaryColor`] image
+/// data, without allocating per pixel.
+///
+/// The mask covers the parent target's [`bounding_box`](Dimensions::bounding_box); coordinates
+/// outside of it are always treated as masked out.
+///
+/// [`BinaryColor`]: crate::pixelcolor::BinaryColor
+pub struct MaskedDrawTarget<'a, T, BO = LittleEndianMsb0> {
+    parent: &'a mut T,
+    mask: &'a [u8],
+    byte_order: core::marker::PhantomData<BO>,
+}
+
+impl<'a, T, BO> MaskedDrawTarget<'a, T, BO>
+where
+    T: DrawTarget,
+    BO: DataOrder,
+{
+    /// Creates a new masked draw target.
+    ///
+    /// `mask` must contain one packed bit per pixel of `parent`'s bounding box, in row-major
+    /// order, using the bit layout described by `BO`.
+    pub fn new(parent: &'a mut T, mask: &'a [u8]) -> Self {
+        Self {
+            parent,
+            mask,
+            byte_order: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Returns `true` if the mask bit for `position` is set.
+///
+/// Takes `area` and `mask` by value rather than `&MaskedDrawTarget` so it can be used from
+/// inside a closure without holding a borrow of `self` alive across the `draw_iter` call that
+/// consumes the closure's iterator.
+fn is_unmasked<BO: DataOrder>(area: Rectangle, mask: &[u8], position: Point) -> bool {
+    if !area.contains(position) {
+        return false;
+    }
+
+    let relative = position - area.top_left;
+    let index = relative.y as usize * area.size.width as usize + relative.x as usize;
+
+    RawU1::load::<BO>(mask, index)
+        .map(|bit| bit.into_inner() != 0)
+        .unwrap_or(false)
+}
+
+#[maybe_async::maybe_async(AFIT)]
+impl<T, BO> DrawTarget for MaskedDrawTarget<'_, T, BO>
+where
+    T: DrawTarget,
+    BO: DataOrder,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    async fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.parent.bounding_box();
+        let mask = self.mask;
+
+        let filtered = pixels
+            .into_iter()
+            .filter(move |Pixel(position, _)| is_unmasked::<BO>(area, mask, *position));
+
+        self.parent.draw_iter(filtered).await
+    }
+
+    async fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.draw_iter(colors.into_iter().into_pixels(area)).await
+    }
+
+    async fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        let mask_area = self.parent.bounding_box();
+        let mask = self.mask;
+
+        self.draw_iter(
+            area.points()
+                .filter(move |&position| is_unmasked::<BO>(mask_area, mask, position))
+                .map(move |position| Pixel(position, color)),
+        )
+        .await
+    }
+
+    async fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.parent.bounding_box();
+        self.fill_solid(&area, color).await
+    }
+}
+
+impl<T: DrawTarget, BO> Dimensions for MaskedDrawTarget<'_, T, BO> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{OriginDimensions, Size},
+        pixelcolor::{raw::LittleEndianMsb0, Rgb888},
+    };
+
+    struct TestTarget {
+        pixels: [[Rgb888; 4]; 1],
+    }
+
+    impl TestTarget {
+        fn new(fill: Rgb888) -> Self {
+            Self {
+                pixels: [[fill; 4]; 1],
+            }
+        }
+    }
+
+    impl OriginDimensions for TestTarget {
+        fn size(&self) -> Size {
+            Size::new(4, 1)
+        }
+    }
+
+    impl DrawTarget for TestTarget {
+        type Color = Rgb888;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(p, color) in pixels {
+                if let Some(pixel) = self.pixels[0].get_mut(p.x as usize) {
+                    *pixel = color;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn only_masked_in_pixels_are_forwarded() {
+        let mut target = TestTarget::new(Rgb888::new(0, 0, 0));
+        // Mask out everything except x == 1 and x == 2 (bits read most-significant-first).
+        let mask = [0b0110_0000u8];
+
+        {
+            let mut masked = MaskedDrawTarget::<_, LittleEndianMsb0>::new(&mut target, &mask);
+
+            masked
+                .fill_solid(&Rectangle::new(Point::zero(), Size::new(4, 1)), Rgb888::new(255, 0, 0))
+                .unwrap();
+        }
+
+        assert_eq!(target.pixels[0][0], Rgb888::new(0, 0, 0));
+        assert_eq!(target.pixels[0][1], Rgb888::new(255, 0, 0));
+        assert_eq!(target.pixels[0][2], Rgb888::new(255, 0, 0));
+        assert_eq!(target.pixels[0][3], Rgb888::new(0, 0, 0));
+    }
+
+    #[test]
+    fn positions_outside_the_bounding_box_are_masked_out() {
+        let mut target = TestTarget::new(Rgb888::new(0, 0, 0));
+        let mask = [0b1111_0000u8];
+        let mut masked = MaskedDrawTarget::<_, LittleEndianMsb0>::new(&mut target, &mask);
+
+        masked
+            .draw_iter(core::iter::once(Pixel(
+                Point::new(10, 10),
+                Rgb888::new(255, 255, 255),
+            )))
+            .unwrap();
+
+        assert_eq!(target.pixels[0], [Rgb888::new(0, 0, 0); 4]);
+    }
+}
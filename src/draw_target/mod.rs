@@ -0,0 +1,5 @@
+mod compositing;
+mod masked;
+
+pub use compositing::CompositingDrawTarget;
+pub use masked::MaskedDrawTarget;
@@ -0,0 +1,3 @@
+mod palette_image_raw;
+
+pub use palette_image_raw::ImageRawPalette;
@@ -0,0 +1,200 @@
+use core::marker::PhantomData;
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    pixelcolor::{
+        raw::{DataOrder, LittleEndianMsb0, OutOfBoundsError, RawData},
+        PaletteColor, PixelColor,
+    },
+    Pixel,
+};
+
+/// Palette-backed raw image data.
+///
+/// Like [`ImageRaw`](super::ImageRaw), `ImageRawPalette` wraps a packed buffer of sub-byte
+/// samples and decodes it one row at a time, padding each scanline out to a whole byte the same
+/// way. The difference is what a sample means: here it's an index into `palette`, looked up and
+/// converted to a real color as each pixel is iterated, rather than colour data in its own
+/// right. That indirection is what lets a tiny 1/2/4/8-bit buffer address any up-to-256 entry
+/// color table instead of a fixed `Gray`/`Rgb` interpretation of its bits, without ever
+/// allocating a buffer of decoded colors.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     image::ImageRawPalette,
+///     mock_display::MockDisplay,
+///     pixelcolor::{raw::RawU2, Rgb888},
+///     prelude::*,
+/// };
+///
+/// const PALETTE: [Rgb888; 4] = [
+///     Rgb888::new(0, 0, 0),
+///     Rgb888::new(255, 0, 0),
+///     Rgb888::new(0, 255, 0),
+///     Rgb888::new(0, 0, 255),
+/// ];
+///
+/// // Each byte packs four 2-bit palette indices.
+/// let data = [0b00_01_10_11];
+/// let image = ImageRawPalette::<RawU2, _, 4>::new(&data, Size::new(4, 1), &PALETTE).unwrap();
+///
+/// let mut display = MockDisplay::<Rgb888>::new();
+/// image.draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+pub struct ImageRawPalette<'a, R, C, const N: usize, BO = LittleEndianMsb0> {
+    data: &'a [u8],
+    size: Size,
+    palette: &'a [C; N],
+    raw_type: PhantomData<R>,
+    byte_order: PhantomData<BO>,
+}
+
+impl<'a, R, C, const N: usize, BO> ImageRawPalette<'a, R, C, N, BO>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+    BO: DataOrder,
+{
+    /// Creates a new palette-backed image.
+    ///
+    /// `data` must contain one packed index per pixel, using the bit layout described by `BO`,
+    /// with each row padded out to a whole byte (the same layout [`ImageRaw`](super::ImageRaw)
+    /// uses). The returned image borrows `palette` and resolves indices into colors from it on
+    /// demand.
+    ///
+    /// Returns [`OutOfBoundsError`] if `data` is too short to hold `size.height` rows of
+    /// `size.width` packed indices.
+    pub fn new(data: &'a [u8], size: Size, palette: &'a [C; N]) -> Result<Self, OutOfBoundsError> {
+        let required_len = bytes_per_row::<R>(size.width) * size.height as usize;
+
+        if data.len() < required_len {
+            return Err(OutOfBoundsError);
+        }
+
+        Ok(Self {
+            data,
+            size,
+            palette,
+            raw_type: PhantomData,
+            byte_order: PhantomData,
+        })
+    }
+
+    /// Returns an iterator over the pixels in this image, resolving each packed index through
+    /// the palette on demand.
+    pub fn pixels(&self) -> impl Iterator<Item = Pixel<C>> + '_ {
+        let indices_per_row = bytes_per_row::<R>(self.size.width) * 8 / R::BITS_PER_PIXEL;
+
+        (0..self.size.height).flat_map(move |y| {
+            (0..self.size.width).map(move |x| {
+                let index = y as usize * indices_per_row + x as usize;
+                let raw = R::load::<BO>(self.data, index)
+                    .expect("data was validated to hold this many rows in Self::new");
+
+                let color = PaletteColor::new(raw, self.palette).color();
+
+                Pixel(Point::new(x as i32, y as i32), color)
+            })
+        })
+    }
+}
+
+/// Returns the number of bytes a single row of `width` packed `R` samples occupies once padded
+/// out to a whole byte, mirroring the row layout `ImageRaw` uses.
+fn bytes_per_row<R: RawData>(width: u32) -> usize {
+    (width as usize * R::BITS_PER_PIXEL + 7) / 8
+}
+
+impl<R, C, const N: usize, BO> OriginDimensions for ImageRawPalette<'_, R, C, N, BO> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+#[maybe_async::maybe_async(AFIT)]
+impl<R, C, const N: usize, BO> ImageRawPalette<'_, R, C, N, BO>
+where
+    R: RawData<Storage = u8>,
+    C: PixelColor,
+    BO: DataOrder,
+{
+    /// Draws this image to `target`.
+    pub async fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.pixels()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock_display::MockDisplay, pixelcolor::raw::RawU2, pixelcolor::Rgb888};
+
+    const PALETTE: [Rgb888; 4] = [
+        Rgb888::new(0, 0, 0),
+        Rgb888::new(255, 0, 0),
+        Rgb888::new(0, 255, 0),
+        Rgb888::new(0, 0, 255),
+    ];
+
+    #[test]
+    fn decodes_packed_indices_through_palette() {
+        let data = [0b00_01_10_11];
+        let image = ImageRawPalette::<RawU2, _, 4>::new(&data, Size::new(4, 1), &PALETTE).unwrap();
+
+        let pixels: Vec<_> = image.pixels().collect();
+
+        assert_eq!(
+            pixels,
+            vec![
+                Pixel(Point::new(0, 0), Rgb888::new(0, 0, 255)),
+                Pixel(Point::new(1, 0), Rgb888::new(0, 255, 0)),
+                Pixel(Point::new(2, 0), Rgb888::new(255, 0, 0)),
+                Pixel(Point::new(3, 0), Rgb888::new(0, 0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_with_leftover_bits_are_padded_to_a_byte_boundary() {
+        // Width 3 at 2 bits per pixel needs 6 bits per row, but each row still occupies a whole
+        // byte: the last 2 bits of each row's byte are padding, not the next row's first index.
+        let data = [0b11_10_01_00, 0b00_01_10_00];
+        let image = ImageRawPalette::<RawU2, _, 4>::new(&data, Size::new(3, 2), &PALETTE).unwrap();
+
+        let pixels: Vec<_> = image.pixels().collect();
+
+        assert_eq!(
+            pixels,
+            vec![
+                Pixel(Point::new(0, 0), Rgb888::new(0, 0, 255)),
+                Pixel(Point::new(1, 0), Rgb888::new(0, 255, 0)),
+                Pixel(Point::new(2, 0), Rgb888::new(255, 0, 0)),
+                Pixel(Point::new(0, 1), Rgb888::new(0, 0, 0)),
+                Pixel(Point::new(1, 1), Rgb888::new(255, 0, 0)),
+                Pixel(Point::new(2, 1), Rgb888::new(0, 255, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn new_rejects_data_shorter_than_the_image_needs() {
+        let data = [0u8];
+
+        assert!(ImageRawPalette::<RawU2, _, 4>::new(&data, Size::new(3, 2), &PALETTE).is_err());
+    }
+
+    #[test]
+    fn reports_its_size() {
+        let data = [0u8];
+        let image = ImageRawPalette::<RawU2, _, 4>::new(&data, Size::new(4, 1), &PALETTE).unwrap();
+
+        assert_eq!(image.size(), Size::new(4, 1));
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, iterator::ContiguousIteratorExt,
-    pixelcolor::BinaryColor, primitives::Rectangle, Pixel,
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    iterator::ContiguousIteratorExt,
+    pixelcolor::{BinaryColor, Gray8, GrayColor, RgbColor},
+    primitives::Rectangle,
+    Pixel,
 };
 
 pub struct MonoFontDrawTarget<'a, T, C> {
@@ -150,6 +154,73 @@ impl<T: DrawTarget> DrawTarget for MonoFontDrawTarget<'_, T, Both<T::Color>> {
     }
 }
 
+#[maybe_async::maybe_async(AFIT)]
+impl<T: DrawTarget> DrawTarget for MonoFontDrawTarget<'_, T, Blended<T::Color>>
+where
+    T::Color: RgbColor,
+{
+    type Color = Gray8;
+    type Error = T::Error;
+
+    async fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let Blended(foreground, background) = self.colors;
+
+        self.parent
+            .fill_contiguous(
+                area,
+                colors
+                    .into_iter()
+                    .map(|coverage| blend(foreground, background, coverage)),
+            )
+            .await
+    }
+
+    async fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        unreachable!()
+    }
+
+    async fn fill_solid(
+        &mut self,
+        area: &Rectangle,
+        color: Self::Color,
+    ) -> Result<(), Self::Error> {
+        let Blended(foreground, background) = self.colors;
+
+        self.parent
+            .fill_solid(area, blend(foreground, background, color))
+            .await
+    }
+
+    async fn clear(&mut self, _color: Self::Color) -> Result<(), Self::Error> {
+        unreachable!()
+    }
+}
+
+/// Linearly interpolates between `background` and `foreground` using `coverage` as the blend
+/// factor, rounding each channel to the nearest integer.
+fn blend<C: RgbColor>(foreground: C, background: C, coverage: Gray8) -> C {
+    let cov = u16::from(coverage.luma());
+
+    let channel = |fg: u8, bg: u8| -> u8 {
+        let fg = u16::from(fg);
+        let bg = u16::from(bg);
+
+        (((fg * cov) + (bg * (255 - cov)) + 127) / 255) as u8
+    };
+
+    C::new(
+        channel(foreground.r(), background.r()),
+        channel(foreground.g(), background.g()),
+        channel(foreground.b(), background.b()),
+    )
+}
+
 impl<T: DrawTarget, C> Dimensions for MonoFontDrawTarget<'_, T, C> {
     fn bounding_box(&self) -> Rectangle {
         self.parent.bounding_box()
@@ -159,3 +230,4 @@ impl<T: DrawTarget, C> Dimensions for MonoFontDrawTarget<'_, T, C> {
 pub struct Foreground<C>(pub C);
 pub struct Background<C>(pub C);
 pub struct Both<C>(pub C, pub C);
+pub struct Blended<C>(pub C, pub C);